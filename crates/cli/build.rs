@@ -0,0 +1,21 @@
+use std::env;
+
+// `src/cli.rs` and `src/man_pages.rs` are included textually rather than
+// used as a lib dependency, since this is a binary-only crate and build
+// scripts can't depend on the crate they build.
+include!("src/cli.rs");
+include!("src/man_pages.rs");
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/cli.rs");
+    println!("cargo:rerun-if-changed=src/man_pages.rs");
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    let man_dir = out_dir.join("man");
+
+    let cmd = full_command();
+    let name = cmd.get_name().to_string();
+    if let Err(err) = render_recursive(&cmd, &name, &man_dir) {
+        println!("cargo:warning=failed to generate man pages: {err}");
+    }
+}
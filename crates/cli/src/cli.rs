@@ -0,0 +1,206 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, Command};
+use clap_complete::Shell;
+
+/// Declarative shape of the CLI: names, help text, and argument
+/// definitions only, no business logic. Kept free of the rest of the
+/// crate's modules so `build.rs` can `include!` it to generate man pages
+/// without compiling (or running) the full binary.
+pub const BIN_NAME: &str = "celerrate";
+
+/// `(name, about, builder)` for every subcommand, in help order. The
+/// single source of truth for the lazy (stub) and eager (full) command
+/// trees, and for man page and completion generation.
+pub const SUBCOMMANDS: &[(&str, &str, fn() -> Command)] = &[
+    (
+        "init",
+        "Interactively generate a celerrate.toml configuration file",
+        init_command,
+    ),
+    (
+        "completions",
+        "Generate a shell completion script",
+        completions_command,
+    ),
+    (
+        "man",
+        "Generate roff man pages for celerrate and all of its subcommands",
+        man_command,
+    ),
+    ("fmt", "Format PHP files in place", fmt_command),
+    (
+        "lint",
+        "Report style and static issues in PHP files",
+        lint_command,
+    ),
+    (
+        "watch",
+        "Run fmt and lint continuously as files change",
+        watch_command,
+    ),
+    (
+        "help",
+        "Show help for celerrate or a specific subcommand",
+        help_command,
+    ),
+];
+
+fn base_command() -> Command {
+    Command::new(BIN_NAME)
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("A fast, modern PHP toolchain written in Rust.")
+        // `help` is our own subcommand (see `SUBCOMMANDS`), resolved through
+        // the same stub/full two-pass machinery as everything else; clap's
+        // built-in `help` subcommand would otherwise register under the same
+        // name and panic on a duplicate.
+        .disable_help_subcommand(true)
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .action(ArgAction::Count)
+                .global(true)
+                .help("Increase log verbosity (-v for info, -vv for debug, -vvv for trace)"),
+        )
+        .arg(
+            Arg::new("quiet")
+                .short('q')
+                .long("quiet")
+                .action(ArgAction::SetTrue)
+                .global(true)
+                .help("Suppress everything but errors"),
+        )
+        .arg(
+            Arg::new("log_config")
+                .long("log-config")
+                .value_name("PATH")
+                .value_parser(clap::value_parser!(PathBuf))
+                .global(true)
+                .help(
+                    "Load logger configuration (appenders, rolling files, per-module levels) \
+                     from a YAML file",
+                ),
+        )
+}
+
+fn init_command() -> Command {
+    Command::new("init")
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .action(ArgAction::SetTrue)
+                .help("Overwrite celerrate.toml if it already exists"),
+        )
+        .arg(
+            Arg::new("yes")
+                .long("yes")
+                .action(ArgAction::SetTrue)
+                .help("Skip prompts and write sensible defaults (for CI/non-interactive use)"),
+        )
+}
+
+fn completions_command() -> Command {
+    Command::new("completions").arg(
+        Arg::new("shell")
+            .value_parser(clap::value_parser!(Shell))
+            .required(true)
+            .help("Shell to generate completions for"),
+    )
+}
+
+fn man_command() -> Command {
+    Command::new("man").arg(
+        Arg::new("out_dir")
+            .long("out-dir")
+            .value_parser(clap::value_parser!(PathBuf))
+            .default_value("man")
+            .help("Directory to write the generated .1 files into"),
+    )
+}
+
+fn paths_arg(help: &'static str) -> Arg {
+    Arg::new("paths")
+        .value_parser(clap::value_parser!(PathBuf))
+        .action(ArgAction::Append)
+        .help(help)
+}
+
+fn fmt_command() -> Command {
+    Command::new("fmt")
+        .arg(paths_arg(
+            "Files or directories to format (defaults to the configured source directories)",
+        ))
+        .arg(
+            Arg::new("check")
+                .long("check")
+                .action(ArgAction::SetTrue)
+                .help("Only report files that would change, without writing them; exits non-zero on diffs"),
+        )
+}
+
+fn lint_command() -> Command {
+    Command::new("lint").arg(paths_arg(
+        "Files or directories to lint (defaults to the configured source directories)",
+    ))
+}
+
+fn watch_command() -> Command {
+    Command::new("watch").arg(paths_arg(
+        "Files or directories to watch (defaults to the configured source directories)",
+    ))
+}
+
+fn help_command() -> Command {
+    Command::new("help").arg(
+        Arg::new("subcommand").help("Subcommand to show help for (omit for celerrate's own help)"),
+    )
+}
+
+/// The full, eager command tree: every subcommand fully defined. Used by
+/// `man`, `completions`, and `build.rs` — contexts that need the whole CLI
+/// documented or rendered up front.
+pub fn full_command() -> Command {
+    let mut cmd = base_command()
+        .subcommand_required(true)
+        .arg_required_else_help(true);
+    for (name, about, build) in SUBCOMMANDS {
+        cmd = cmd.subcommand(build().name(*name).about(*about));
+    }
+    cmd
+}
+
+/// The thin command tree: each subcommand appears only as a name (for help
+/// and matching) and swallows its own arguments un-validated. Used for the
+/// first, cheap parse pass so only the selected subcommand's full
+/// definition — and whatever setup it implies — gets built at all.
+pub fn stub_command() -> Command {
+    let mut cmd = base_command()
+        .subcommand_required(true)
+        .arg_required_else_help(true);
+    for (name, about, _) in SUBCOMMANDS {
+        cmd = cmd.subcommand(
+            Command::new(*name)
+                .about(*about)
+                .disable_help_flag(true)
+                .arg(
+                    Arg::new("rest")
+                        .action(ArgAction::Append)
+                        .num_args(0..)
+                        .allow_hyphen_values(true)
+                        .trailing_var_arg(true),
+                ),
+        );
+    }
+    cmd
+}
+
+/// Build the full definition for just `name`, nested under the same global
+/// args as [`full_command`]. Used for the second parse pass once the stub
+/// pass has told us which subcommand was selected.
+pub fn selected_command(name: &str) -> Option<Command> {
+    let (name, about, build) = SUBCOMMANDS
+        .iter()
+        .find(|(candidate, _, _)| *candidate == name)?;
+    Some(base_command().subcommand(build().name(*name).about(*about)))
+}
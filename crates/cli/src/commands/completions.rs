@@ -0,0 +1,17 @@
+use clap::ArgMatches;
+use clap_complete::{generate, Shell};
+
+pub fn dispatch(matches: &ArgMatches) {
+    let shell = *matches
+        .get_one::<Shell>("shell")
+        .expect("shell is required");
+    run(shell);
+}
+
+/// Render a shell completion script for `shell` to stdout, e.g.
+/// `celerrate completions zsh > _celerrate`.
+fn run(shell: Shell) {
+    let mut cmd = crate::cli::full_command();
+    let name = cmd.get_name().to_string();
+    generate(shell, &mut cmd, name, &mut std::io::stdout());
+}
@@ -0,0 +1,147 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+use rayon::prelude::*;
+
+use crate::config::Config;
+use crate::discovery::discover_php_files;
+
+pub fn dispatch(config: &Config, matches: &ArgMatches) {
+    let paths: Vec<PathBuf> = matches
+        .get_many::<PathBuf>("paths")
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default();
+    let check = matches.get_flag("check");
+
+    let summary = run(config, &paths, check);
+    if summary.errors > 0 || (check && summary.changed > 0) {
+        std::process::exit(1);
+    }
+}
+
+/// Outcome of a `fmt` run, used both to print the final summary and to
+/// decide the process exit code for `--check`.
+pub struct FmtSummary {
+    pub checked: usize,
+    pub changed: usize,
+    pub errors: usize,
+}
+
+/// Format `paths` (or the configured source directories when empty) in
+/// place, across a thread pool. With `check`, nothing is written; a file
+/// that would change is still counted so CI can fail the build.
+pub(crate) fn run(config: &Config, paths: &[PathBuf], check: bool) -> FmtSummary {
+    if !config.formatter_enabled() {
+        log::warn!("formatter is disabled in celerrate.toml; skipping");
+        return FmtSummary {
+            checked: 0,
+            changed: 0,
+            errors: 0,
+        };
+    }
+
+    let (files, missing) = discover_php_files(config, paths);
+    let results: Vec<FileResult> = files
+        .par_iter()
+        .map(|path| format_file(path, check))
+        .collect();
+
+    let mut summary = FmtSummary {
+        checked: results.len(),
+        changed: 0,
+        errors: missing.len(),
+    };
+
+    for path in &missing {
+        log::error!("{} does not exist", path.display());
+    }
+
+    for result in results {
+        match result {
+            FileResult::Unchanged => {}
+            FileResult::Changed(path) => {
+                log::info!(
+                    "{} {}",
+                    if check { "would format" } else { "formatted" },
+                    path.display()
+                );
+                summary.changed += 1;
+            }
+            FileResult::Error(path, err) => {
+                log::error!("failed to format {}: {err}", path.display());
+                summary.errors += 1;
+            }
+        }
+    }
+
+    log::info!(
+        "fmt: {} checked, {} changed, {} error(s)",
+        summary.checked,
+        summary.changed,
+        summary.errors
+    );
+
+    summary
+}
+
+enum FileResult {
+    Unchanged,
+    Changed(PathBuf),
+    Error(PathBuf, std::io::Error),
+}
+
+fn format_file(path: &Path, check: bool) -> FileResult {
+    let original = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => return FileResult::Error(path.to_path_buf(), err),
+    };
+
+    let formatted = format_source(&original);
+    if formatted == original {
+        return FileResult::Unchanged;
+    }
+
+    if !check {
+        if let Err(err) = fs::write(path, &formatted) {
+            return FileResult::Error(path.to_path_buf(), err);
+        }
+    }
+
+    FileResult::Changed(path.to_path_buf())
+}
+
+/// Normalize trailing whitespace and collapse trailing blank lines to a
+/// single newline.
+///
+/// This is the initial, minimal rule set; real PHP-aware formatting
+/// (indentation, brace style, import ordering, ...) lands incrementally on
+/// top of this pipeline.
+fn format_source(source: &str) -> String {
+    let mut lines: Vec<&str> = source.lines().map(|line| line.trim_end()).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let mut formatted = lines.join("\n");
+    formatted.push('\n');
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trims_trailing_whitespace_and_blank_lines() {
+        assert_eq!(
+            format_source("<?php\necho 'hi';   \n\n\n"),
+            "<?php\necho 'hi';\n"
+        );
+    }
+
+    #[test]
+    fn leaves_already_clean_source_untouched() {
+        assert_eq!(format_source("<?php\necho 'hi';\n"), "<?php\necho 'hi';\n");
+    }
+}
@@ -0,0 +1,26 @@
+use clap::ArgMatches;
+
+pub fn dispatch(matches: &ArgMatches) {
+    let target = matches.get_one::<String>("subcommand").map(String::as_str);
+    run(target);
+}
+
+/// Print `celerrate`'s own help, or a specific subcommand's, from the full
+/// (eager) command tree — the stub tree only knows subcommand names, not
+/// their arguments.
+fn run(target: Option<&str>) {
+    let mut cmd = crate::cli::full_command();
+
+    let Some(name) = target else {
+        cmd.print_help().expect("writing to stdout succeeds");
+        return;
+    };
+
+    match cmd.find_subcommand_mut(name) {
+        Some(sub) => sub.print_help().expect("writing to stdout succeeds"),
+        None => {
+            log::error!("unrecognized subcommand '{name}'");
+            std::process::exit(1);
+        }
+    }
+}
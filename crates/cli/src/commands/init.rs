@@ -0,0 +1,141 @@
+use std::io::{self, Write};
+use std::path::Path;
+
+use clap::ArgMatches;
+use serde::Serialize;
+
+use crate::config::{Config, CONFIG_FILE};
+
+#[derive(Serialize)]
+struct InitConfig {
+    php_version: String,
+    source_dirs: Vec<String>,
+    vendor_autoload: String,
+    formatter: FormatterConfig,
+    linter: LinterConfig,
+}
+
+#[derive(Serialize)]
+struct FormatterConfig {
+    enabled: bool,
+}
+
+#[derive(Serialize)]
+struct LinterConfig {
+    enabled: bool,
+}
+
+impl From<&Config> for InitConfig {
+    fn from(config: &Config) -> Self {
+        InitConfig {
+            php_version: config.php_version().to_string(),
+            source_dirs: config.source_dirs().to_vec(),
+            vendor_autoload: config.vendor_autoload().to_string(),
+            formatter: FormatterConfig {
+                enabled: config.formatter_enabled(),
+            },
+            linter: LinterConfig {
+                enabled: config.linter_enabled(),
+            },
+        }
+    }
+}
+
+pub fn dispatch(config: &Config, matches: &ArgMatches) {
+    let force = matches.get_flag("force");
+    let yes = matches.get_flag("yes");
+    if let Err(err) = run(config, force, yes) {
+        log::error!("{err}");
+        std::process::exit(1);
+    }
+}
+
+/// Run the `init` wizard, writing `celerrate.toml` to the current directory.
+///
+/// `defaults` is the resolved project config (any `celerrate.toml` found
+/// from the current directory upward, else the built-in defaults) and seeds
+/// both the prompts and the `--yes` fast path.
+///
+/// With `yes`, prompts are skipped and the values from `defaults` are
+/// written, so the command stays usable in CI and other non-interactive
+/// contexts. Only the PHP version, source directories, vendor autoload path
+/// and the formatter/linter toggles are written; `exclude` and linter rules
+/// are not.
+fn run(defaults: &Config, force: bool, yes: bool) -> io::Result<()> {
+    let path = Path::new(CONFIG_FILE);
+    if path.exists() && !force {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{CONFIG_FILE} already exists in this directory (use --force to overwrite)"),
+        ));
+    }
+
+    let config = if yes {
+        InitConfig::from(defaults)
+    } else {
+        prompt_for_config(defaults)?
+    };
+
+    let contents =
+        toml::to_string_pretty(&config).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+    std::fs::write(path, contents)?;
+
+    log::info!("wrote {CONFIG_FILE}");
+    Ok(())
+}
+
+fn prompt_for_config(defaults: &Config) -> io::Result<InitConfig> {
+    let php_version = prompt("PHP version", defaults.php_version())?;
+    let source_dirs = prompt(
+        "Source directories (comma-separated)",
+        &defaults.source_dirs().join(","),
+    )?
+    .split(',')
+    .map(|s| s.trim().to_string())
+    .filter(|s| !s.is_empty())
+    .collect();
+    let vendor_autoload = prompt("Vendor autoload path", defaults.vendor_autoload())?;
+    let formatter_enabled = prompt_bool("Enable the formatter", defaults.formatter_enabled())?;
+    let linter_enabled = prompt_bool("Enable the linter", defaults.linter_enabled())?;
+
+    Ok(InitConfig {
+        php_version,
+        source_dirs,
+        vendor_autoload,
+        formatter: FormatterConfig {
+            enabled: formatter_enabled,
+        },
+        linter: LinterConfig {
+            enabled: linter_enabled,
+        },
+    })
+}
+
+/// Print `question`, flush stdout, then read and trim one line from stdin.
+/// An empty answer falls back to `default`.
+fn prompt(question: &str, default: &str) -> io::Result<String> {
+    print!("{question} [{default}]: ");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+
+    Ok(if input.is_empty() {
+        default.to_string()
+    } else {
+        input.to_string()
+    })
+}
+
+fn prompt_bool(question: &str, default: bool) -> io::Result<bool> {
+    let hint = if default { "Y/n" } else { "y/N" };
+    let answer = prompt(&format!("{question} ({hint})"), "")?;
+
+    Ok(match answer.to_lowercase().as_str() {
+        "" => default,
+        "y" | "yes" => true,
+        "n" | "no" => false,
+        _ => default,
+    })
+}
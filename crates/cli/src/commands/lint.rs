@@ -0,0 +1,144 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+use rayon::prelude::*;
+
+use crate::config::Config;
+use crate::discovery::discover_php_files;
+
+pub fn dispatch(config: &Config, matches: &ArgMatches) {
+    let paths: Vec<PathBuf> = matches
+        .get_many::<PathBuf>("paths")
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default();
+
+    let summary = run(config, &paths);
+    if summary.errors > 0 || !summary.issues.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+pub struct LintIssue {
+    pub path: PathBuf,
+    pub line: usize,
+    pub message: String,
+}
+
+/// Outcome of a `lint` run, used both to print the final summary and to
+/// decide the process exit code.
+pub struct LintSummary {
+    pub checked: usize,
+    pub issues: Vec<LintIssue>,
+    pub errors: usize,
+}
+
+/// Lint `paths` (or the configured source directories when empty) with the
+/// built-in rules of [`run_rules`], across a thread pool. The `linter.rules`
+/// list from the config is not consulted.
+pub(crate) fn run(config: &Config, paths: &[PathBuf]) -> LintSummary {
+    if !config.linter_enabled() {
+        log::warn!("linter is disabled in celerrate.toml; skipping");
+        return LintSummary {
+            checked: 0,
+            issues: Vec::new(),
+            errors: 0,
+        };
+    }
+
+    let (files, missing) = discover_php_files(config, paths);
+    let results: Vec<Result<Vec<LintIssue>, (PathBuf, std::io::Error)>> =
+        files.par_iter().map(|path| lint_file(path)).collect();
+
+    let mut summary = LintSummary {
+        checked: results.len(),
+        issues: Vec::new(),
+        errors: missing.len(),
+    };
+
+    for path in &missing {
+        log::error!("{} does not exist", path.display());
+    }
+
+    for result in results {
+        match result {
+            Ok(issues) => summary.issues.extend(issues),
+            Err((path, err)) => {
+                log::error!("failed to lint {}: {err}", path.display());
+                summary.errors += 1;
+            }
+        }
+    }
+
+    for issue in &summary.issues {
+        log::warn!("{}:{}: {}", issue.path.display(), issue.line, issue.message);
+    }
+
+    log::info!(
+        "lint: {} checked, {} issue(s), {} error(s)",
+        summary.checked,
+        summary.issues.len(),
+        summary.errors
+    );
+
+    summary
+}
+
+fn lint_file(path: &Path) -> Result<Vec<LintIssue>, (PathBuf, std::io::Error)> {
+    let contents = fs::read_to_string(path).map_err(|err| (path.to_path_buf(), err))?;
+    Ok(run_rules(path, &contents))
+}
+
+/// The initial rule set: trailing whitespace and tab-indented lines.
+/// Grows as the linter gains PHP-aware static checks.
+fn run_rules(path: &Path, contents: &str) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+
+        if line != line.trim_end() {
+            issues.push(LintIssue {
+                path: path.to_path_buf(),
+                line: line_number,
+                message: "trailing whitespace".to_string(),
+            });
+        }
+
+        if line.starts_with('\t') {
+            issues.push(LintIssue {
+                path: path.to_path_buf(),
+                line: line_number,
+                message: "line indented with tabs".to_string(),
+            });
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_trailing_whitespace() {
+        let issues = run_rules(Path::new("Foo.php"), "<?php\necho 'hi';  \n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, 2);
+        assert_eq!(issues[0].message, "trailing whitespace");
+    }
+
+    #[test]
+    fn flags_tab_indentation() {
+        let issues = run_rules(Path::new("Foo.php"), "<?php\n\techo 'hi';\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, 2);
+        assert_eq!(issues[0].message, "line indented with tabs");
+    }
+
+    #[test]
+    fn clean_source_has_no_issues() {
+        assert!(run_rules(Path::new("Foo.php"), "<?php\necho 'hi';\n").is_empty());
+    }
+}
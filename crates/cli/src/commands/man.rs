@@ -0,0 +1,25 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+
+use crate::man_pages::render_recursive;
+
+pub fn dispatch(matches: &ArgMatches) {
+    let out_dir = matches
+        .get_one::<PathBuf>("out_dir")
+        .expect("has a default value")
+        .clone();
+
+    if let Err(err) = run(&out_dir) {
+        log::error!("{err}");
+        std::process::exit(1);
+    }
+}
+
+/// Write a `.1` man page for `celerrate` and every subcommand into `out_dir`.
+fn run(out_dir: &Path) -> io::Result<()> {
+    let cmd = crate::cli::full_command();
+    let name = cmd.get_name().to_string();
+    render_recursive(&cmd, &name, out_dir)
+}
@@ -0,0 +1,7 @@
+pub mod completions;
+pub mod fmt;
+pub mod help;
+pub mod init;
+pub mod lint;
+pub mod man;
+pub mod watch;
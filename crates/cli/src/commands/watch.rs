@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use clap::ArgMatches;
+use notify::{RecursiveMode, Watcher};
+use notify_debouncer_mini::{new_debouncer, DebouncedEventKind};
+
+use super::{fmt, lint};
+use crate::config::Config;
+
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+pub fn dispatch(config: &Config, matches: &ArgMatches) {
+    let paths: Vec<PathBuf> = matches
+        .get_many::<PathBuf>("paths")
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default();
+
+    if let Err(err) = run(config, &paths) {
+        log::error!("{err}");
+        std::process::exit(1);
+    }
+}
+
+/// Watch `paths` (or the configured source directories when empty) and
+/// re-run fmt+lint on just the
+/// files that changed each time the filesystem settles for `DEBOUNCE`.
+fn run(config: &Config, paths: &[PathBuf]) -> notify::Result<()> {
+    let roots: Vec<PathBuf> = if paths.is_empty() {
+        config.source_dirs().iter().map(PathBuf::from).collect()
+    } else {
+        paths.to_vec()
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let mut debouncer = new_debouncer(DEBOUNCE, tx)?;
+    for root in &roots {
+        debouncer.watcher().watch(root, RecursiveMode::Recursive)?;
+    }
+
+    log::info!(
+        "watching {} for changes (fmt + lint on save)...",
+        roots
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    for result in rx {
+        let events = match result {
+            Ok(events) => events,
+            Err(err) => {
+                log::error!("watch error: {err:?}");
+                continue;
+            }
+        };
+
+        let affected: Vec<PathBuf> = events
+            .into_iter()
+            .filter(|event| event.kind == DebouncedEventKind::Any)
+            .map(|event| event.path)
+            .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("php"))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        if affected.is_empty() {
+            continue;
+        }
+
+        let fmt_summary = fmt::run(config, &affected, false);
+        let lint_summary = lint::run(config, &affected);
+        log::info!(
+            "re-ran fmt+lint on {} file(s): {} formatted, {} lint issue(s)",
+            fmt_summary.checked,
+            fmt_summary.changed,
+            lint_summary.issues.len()
+        );
+    }
+
+    Ok(())
+}
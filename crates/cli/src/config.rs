@@ -0,0 +1,220 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Name of the project configuration file, looked up from the current
+/// directory upward to the project root.
+pub const CONFIG_FILE: &str = "celerrate.toml";
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct RawConfig {
+    php_version: Option<String>,
+    source_dirs: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    vendor_autoload: Option<String>,
+    formatter: Option<RawFormatterConfig>,
+    linter: Option<RawLinterConfig>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct RawFormatterConfig {
+    enabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct RawLinterConfig {
+    enabled: Option<bool>,
+    rules: Option<Vec<String>>,
+}
+
+/// Resolved project configuration: `celerrate.toml` merged over built-in
+/// defaults. Every command should go through this instead of reading the
+/// file itself.
+#[derive(Debug, Clone)]
+pub struct Config {
+    php_version: String,
+    source_dirs: Vec<String>,
+    exclude: Vec<String>,
+    vendor_autoload: String,
+    formatter_enabled: bool,
+    linter_enabled: bool,
+    linter_rules: Vec<String>,
+}
+
+impl Config {
+    pub fn php_version(&self) -> &str {
+        &self.php_version
+    }
+
+    pub fn source_dirs(&self) -> &[String] {
+        &self.source_dirs
+    }
+
+    pub fn exclude(&self) -> &[String] {
+        &self.exclude
+    }
+
+    pub fn vendor_autoload(&self) -> &str {
+        &self.vendor_autoload
+    }
+
+    pub fn formatter_enabled(&self) -> bool {
+        self.formatter_enabled
+    }
+
+    pub fn linter_enabled(&self) -> bool {
+        self.linter_enabled
+    }
+
+    pub fn linter_rules(&self) -> &[String] {
+        &self.linter_rules
+    }
+
+    fn merge(raw: RawConfig) -> Self {
+        let defaults = Config::default();
+        Config {
+            php_version: raw.php_version.unwrap_or(defaults.php_version),
+            source_dirs: raw.source_dirs.unwrap_or(defaults.source_dirs),
+            exclude: raw.exclude.unwrap_or(defaults.exclude),
+            vendor_autoload: raw.vendor_autoload.unwrap_or(defaults.vendor_autoload),
+            formatter_enabled: raw
+                .formatter
+                .and_then(|f| f.enabled)
+                .unwrap_or(defaults.formatter_enabled),
+            linter_enabled: raw
+                .linter
+                .as_ref()
+                .and_then(|l| l.enabled)
+                .unwrap_or(defaults.linter_enabled),
+            linter_rules: raw
+                .linter
+                .and_then(|l| l.rules)
+                .unwrap_or(defaults.linter_rules),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            php_version: "8.3".to_string(),
+            source_dirs: vec!["src".to_string()],
+            exclude: vec!["vendor".to_string()],
+            vendor_autoload: "vendor/autoload.php".to_string(),
+            formatter_enabled: true,
+            linter_enabled: true,
+            linter_rules: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_falls_back_to_defaults_for_absent_fields() {
+        let config = Config::merge(RawConfig::default());
+        assert_eq!(config.php_version(), Config::default().php_version());
+        assert_eq!(config.source_dirs(), Config::default().source_dirs());
+        assert!(config.formatter_enabled());
+        assert!(config.linter_enabled());
+    }
+
+    #[test]
+    fn merge_prefers_values_present_in_the_file() {
+        let raw = RawConfig {
+            php_version: Some("7.4".to_string()),
+            formatter: Some(RawFormatterConfig {
+                enabled: Some(false),
+            }),
+            linter: Some(RawLinterConfig {
+                enabled: Some(false),
+                rules: Some(vec!["no-eval".to_string()]),
+            }),
+            ..RawConfig::default()
+        };
+
+        let config = Config::merge(raw);
+
+        assert_eq!(config.php_version(), "7.4");
+        assert!(!config.formatter_enabled());
+        assert!(!config.linter_enabled());
+        assert_eq!(config.linter_rules(), ["no-eval"]);
+        // Fields absent from the file still fall back to defaults.
+        assert_eq!(config.source_dirs(), Config::default().source_dirs());
+    }
+}
+
+/// An error encountered while locating or parsing `celerrate.toml`.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())
+            }
+            ConfigError::Parse { path, source } => {
+                write!(f, "failed to parse {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Load `celerrate.toml`, walking up from the current directory to the
+/// filesystem root. Returns built-in defaults if no config file is found
+/// anywhere above the current directory.
+pub fn load() -> Result<Config, ConfigError> {
+    match find_config_file()? {
+        Some(path) => load_from(&path),
+        None => Ok(Config::default()),
+    }
+}
+
+fn find_config_file() -> Result<Option<PathBuf>, ConfigError> {
+    let mut dir = std::env::current_dir().map_err(|source| ConfigError::Io {
+        path: PathBuf::from("."),
+        source,
+    })?;
+
+    loop {
+        let candidate = dir.join(CONFIG_FILE);
+        if candidate.is_file() {
+            return Ok(Some(candidate));
+        }
+        if !dir.pop() {
+            return Ok(None);
+        }
+    }
+}
+
+fn load_from(path: &Path) -> Result<Config, ConfigError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let raw: RawConfig = toml::from_str(&contents).map_err(|source| ConfigError::Parse {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    Ok(Config::merge(raw))
+}
@@ -0,0 +1,107 @@
+use std::path::{Path, PathBuf};
+
+use glob::Pattern;
+use walkdir::WalkDir;
+
+use crate::config::Config;
+
+/// Discover `.php` files to process, and any explicitly-named `paths` that
+/// don't exist on disk (so callers can report a clear error instead of
+/// silently finding nothing to do).
+///
+/// When `paths` is empty, walks `config`'s source directories; otherwise
+/// walks exactly the given paths (files are taken as-is). Either way,
+/// anything matching one of `config`'s exclude globs is dropped.
+pub fn discover_php_files(config: &Config, paths: &[PathBuf]) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    let explicit = !paths.is_empty();
+    let roots: Vec<PathBuf> = if explicit {
+        paths.to_vec()
+    } else {
+        config.source_dirs().iter().map(PathBuf::from).collect()
+    };
+
+    let exclude: Vec<Pattern> = config
+        .exclude()
+        .iter()
+        .filter_map(|pattern| Pattern::new(pattern).ok())
+        .collect();
+
+    let mut files = Vec::new();
+    let mut missing = Vec::new();
+    for root in roots {
+        if !root.exists() {
+            if explicit {
+                missing.push(root);
+            }
+            continue;
+        }
+
+        if root.is_file() {
+            if !is_excluded(&root, &exclude) {
+                files.push(root);
+            }
+            continue;
+        }
+
+        for entry in WalkDir::new(&root).into_iter().filter_map(Result::ok) {
+            let path = entry.path();
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            if path.extension().and_then(|ext| ext.to_str()) != Some("php") {
+                continue;
+            }
+            if is_excluded(path, &exclude) {
+                continue;
+            }
+            files.push(path.to_path_buf());
+        }
+    }
+
+    files.sort();
+    files.dedup();
+    (files, missing)
+}
+
+/// A path is excluded if any exclude glob matches it in full (for
+/// hand-written globs like `vendor/**` or `**/*.generated.php`), or
+/// matches one of its individual components (so the common case of a
+/// bare directory name, e.g. `vendor`, excludes that directory at any
+/// depth without requiring a `**` pattern).
+fn is_excluded(path: &Path, exclude: &[Pattern]) -> bool {
+    exclude.iter().any(|pattern| {
+        pattern.matches_path(path)
+            || path
+                .components()
+                .any(|component| pattern.matches(&component.as_os_str().to_string_lossy()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(globs: &[&str]) -> Vec<Pattern> {
+        globs
+            .iter()
+            .map(|glob| Pattern::new(glob).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn bare_directory_name_excludes_it_at_any_depth() {
+        let exclude = patterns(&["vendor"]);
+
+        assert!(is_excluded(Path::new("vendor/Foo.php"), &exclude));
+        assert!(is_excluded(Path::new("src/vendor/Foo.php"), &exclude));
+        assert!(!is_excluded(Path::new("src/Foo.php"), &exclude));
+    }
+
+    #[test]
+    fn glob_pattern_matches_the_full_path() {
+        let exclude = patterns(&["**/*.generated.php"]);
+
+        assert!(is_excluded(Path::new("src/Foo.generated.php"), &exclude));
+        assert!(!is_excluded(Path::new("src/Foo.php"), &exclude));
+    }
+}
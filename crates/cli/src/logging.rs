@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use log::LevelFilter;
+
+/// Initialize the global logger, before any subcommand runs.
+///
+/// When `log_config` points to a YAML file, the logger is configured from
+/// it via log4rs, so power users can set up appenders, rolling files, and
+/// per-module levels. Otherwise a built-in human-readable format is used,
+/// with its level driven by `verbose`/`quiet`.
+pub fn init(verbose: u8, quiet: bool, log_config: Option<&Path>) {
+    if let Some(path) = log_config {
+        if let Err(err) = log4rs::init_file(path, Default::default()) {
+            eprintln!("error: failed to load log config {}: {err}", path.display());
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    env_logger::Builder::new()
+        .filter_level(level_filter(verbose, quiet))
+        .format_timestamp(None)
+        .format_target(false)
+        .init();
+}
+
+fn level_filter(verbose: u8, quiet: bool) -> LevelFilter {
+    if quiet {
+        return LevelFilter::Error;
+    }
+
+    match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
@@ -1,23 +1,55 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
-#[derive(Parser)]
-#[command(name = "Celerrate")]
-#[command(version = env!("CARGO_PKG_VERSION"))]
-#[command(about = "A fast, modern PHP toolchain written in Rust.", long_about = None)]
-struct Cli {
-    #[command(subcommand)]
-    command: Commands,
-}
-
-#[derive(Subcommand)]
-enum Commands {
-    Init,
-}
+mod cli;
+mod commands;
+mod config;
+mod discovery;
+mod logging;
+mod man_pages;
 
 fn main() {
-    let cli = Cli::parse();
+    let thin_matches = cli::stub_command().get_matches();
+
+    let verbose = thin_matches.get_count("verbose");
+    let quiet = thin_matches.get_flag("quiet");
+    let log_config = thin_matches.get_one::<PathBuf>("log_config").cloned();
+    logging::init(verbose, quiet, log_config.as_deref());
+
+    let (name, _) = thin_matches
+        .subcommand()
+        .expect("clap requires a subcommand");
+
+    // Re-parse with only the selected subcommand's full definition built,
+    // so the other subcommands never pay for their arg setup.
+    let full_matches = cli::selected_command(name)
+        .expect("stub and full command trees list the same subcommands")
+        .get_matches();
+    let (_, matches) = full_matches
+        .subcommand()
+        .expect("selected subcommand still matches on the second parse");
+
+    match name {
+        "init" => commands::init::dispatch(&load_config(), matches),
+        "completions" => commands::completions::dispatch(matches),
+        "man" => commands::man::dispatch(matches),
+        "fmt" => commands::fmt::dispatch(&load_config(), matches),
+        "lint" => commands::lint::dispatch(&load_config(), matches),
+        "watch" => commands::watch::dispatch(&load_config(), matches),
+        "help" => commands::help::dispatch(matches),
+        _ => unreachable!("exhaustive over cli::SUBCOMMANDS"),
+    }
+}
 
-    match cli.command {
-        Commands::Init => println!("Initializing celerrate.toml configuration file..."),
+/// Load `celerrate.toml`, exiting with an error if it exists but can't be
+/// read or parsed. Only called for subcommands that actually need config
+/// (`init`, `fmt`, `lint`, `watch`), so the others never pay for it or fail
+/// because of it.
+fn load_config() -> config::Config {
+    match config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            log::error!("{err}");
+            std::process::exit(1);
+        }
     }
 }
@@ -0,0 +1,29 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use clap_mangen::Man;
+
+/// Render a man page for `cmd` into `out_dir` as `<prefix>.1`, then recurse
+/// into every subcommand as `<prefix>-<subcommand>.1`, so each subcommand
+/// gets its own page automatically as the CLI grows.
+///
+/// Shared between the `man` subcommand and `build.rs` (via `include!`), so
+/// packagers can produce man pages without running the binary. `Command` is
+/// referred to by its full path rather than a `use` here, since `build.rs`
+/// also `include!`s `src/cli.rs`, which imports it under the same name.
+pub fn render_recursive(cmd: &clap::Command, prefix: &str, out_dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(out_dir)?;
+
+    let man = Man::new(cmd.clone());
+    let mut buffer = Vec::new();
+    man.render(&mut buffer)?;
+    fs::write(out_dir.join(format!("{prefix}.1")), buffer)?;
+
+    for sub in cmd.get_subcommands() {
+        let sub_prefix = format!("{prefix}-{}", sub.get_name());
+        render_recursive(sub, &sub_prefix, out_dir)?;
+    }
+
+    Ok(())
+}